@@ -123,6 +123,38 @@ fn bench_to_string(b: &mut Bencher) {
     b.iter(|| s.to_string())
 }
 
+// `to_string` on integers skips the `Formatter`; `format!` is the
+// `Display` path it used to go through.
+const INTS: [i32; 8] = [0, 7, -42, 1_234, -56_789, 1_000_000, i32::MIN, i32::MAX];
+
+#[bench]
+fn bench_i32_to_string(b: &mut Bencher) {
+    b.iter(|| {
+        for n in black_box(INTS) {
+            black_box(n.to_string());
+        }
+    })
+}
+
+#[bench]
+fn bench_i32_format(b: &mut Bencher) {
+    b.iter(|| {
+        for n in black_box(INTS) {
+            black_box(format!("{}", n));
+        }
+    })
+}
+
+#[bench]
+fn bench_u64_to_string(b: &mut Bencher) {
+    b.iter(|| black_box(u64::MAX - black_box(12_345)).to_string())
+}
+
+#[bench]
+fn bench_u64_format(b: &mut Bencher) {
+    b.iter(|| format!("{}", black_box(u64::MAX - black_box(12_345))))
+}
+
 #[bench]
 fn bench_insert_char_short(b: &mut Bencher) {
     let s = "Hello, World!";
@@ -533,18 +533,30 @@
 pub use core::fmt::Error;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{write, ArgumentV1, Arguments};
+#[unstable(feature = "fmt_write_uninit", issue = "none")]
+pub use core::fmt::write_to_uninit;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Binary, Octal};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Debug, Display};
+#[unstable(feature = "formatting_options", issue = "none")]
+pub use core::fmt::{DebugAsHex, Sign};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{Formatter, Result, Write};
+#[unstable(feature = "fmt_indented", issue = "none")]
+pub use core::fmt::Indented;
+#[unstable(feature = "lazy_format", issue = "none")]
+pub use core::fmt::{from_fn, FromFn};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerExp, UpperExp};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::fmt::{LowerHex, Pointer, UpperHex};
+#[unstable(feature = "fmt_hex_float", issue = "none")]
+pub use core::fmt::HexFloat;
+#[unstable(feature = "fmt_radix", issue = "none")]
+pub use core::fmt::{radix, RadixFmt};
 
 #[cfg(not(no_global_oom_handling))]
 use crate::string;
@@ -583,3 +595,43 @@ pub fn format(args: Arguments<'_>) -> string::String {
     output.write_fmt(args).expect("a formatting trait implementation returned an error");
     output
 }
+
+/// An adapter that implements [`Write`] for a byte vector.
+///
+/// Formatted output is appended to the vector as UTF-8. This lets
+/// [`write!`] target byte buffers directly, without going through
+/// `std::io` or an intermediate [`String`].
+///
+/// [`write!`]: core::write
+/// [`String`]: string::String
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_write_bytes)]
+/// use std::fmt::{Write, WriteBytes};
+///
+/// let mut frame = vec![0x02];
+/// write!(WriteBytes(&mut frame), "len={}", 42).unwrap();
+/// frame.push(0x03);
+/// assert_eq!(frame, b"\x02len=42\x03");
+/// ```
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "fmt_write_bytes", issue = "none")]
+#[derive(Debug)]
+pub struct WriteBytes<'a>(pub &'a mut crate::vec::Vec<u8>);
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "fmt_write_bytes", issue = "none")]
+impl Write for WriteBytes<'_> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+
+    #[inline]
+    fn reserve_hint(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
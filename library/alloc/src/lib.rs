@@ -97,8 +97,14 @@
 #![feature(exact_size_is_empty)]
 #![feature(exclusive_range_pattern)]
 #![feature(extend_one)]
+#![feature(fmt_hex_float)]
+#![feature(fmt_indented)]
 #![feature(fmt_internals)]
+#![feature(fmt_radix)]
+#![feature(fmt_write_reserve_hint)]
+#![feature(fmt_write_uninit)]
 #![feature(fn_traits)]
+#![feature(formatting_options)]
 #![feature(fundamental)]
 #![feature(inplace_iteration)]
 // Technically, this is a bug in rustdoc: rustdoc sees the documentation on `#[lang = slice_alloc]`
@@ -109,6 +115,7 @@
 #![feature(iter_zip)]
 #![feature(lang_items)]
 #![feature(layout_for_ptr)]
+#![feature(lazy_format)]
 #![feature(negative_impls)]
 #![feature(never_type)]
 #![feature(nll)]
@@ -2437,6 +2437,54 @@ fn to_string(&self) -> String {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[stable(feature = "bool_to_string_specialization", since = "1.55.0")]
+impl ToString for bool {
+    #[inline]
+    fn to_string(&self) -> String {
+        String::from(if *self { "true" } else { "false" })
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+macro_rules! impl_to_string_int {
+    ($($t:ty)*) => {$(
+        #[stable(feature = "int_to_string_specialization", since = "1.55.0")]
+        impl ToString for $t {
+            #[inline]
+            fn to_string(&self) -> String {
+                use core::fmt::DecimalInt;
+                use core::mem::MaybeUninit;
+
+                let mut buf = [MaybeUninit::uninit(); core::fmt::DECIMAL_INT_BUF_LEN];
+                String::from(self.write_decimal(&mut buf))
+            }
+        }
+    )*};
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl_to_string_int! { u16 u32 u64 u128 usize i16 i32 i64 i128 isize }
+
+#[cfg(not(no_global_oom_handling))]
+macro_rules! impl_to_string_float {
+    ($($t:ty)*) => {$(
+        #[stable(feature = "float_to_string_specialization", since = "1.55.0")]
+        impl ToString for $t {
+            #[inline]
+            fn to_string(&self) -> String {
+                let mut buf = String::new();
+                core::fmt::float_to_decimal_display(&mut buf, self)
+                    .expect("a Display implementation returned an error unexpectedly");
+                buf
+            }
+        }
+    )*};
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl_to_string_float! { f32 f64 }
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "str_to_string_specialization", since = "1.9.0")]
 impl ToString for str {
@@ -2717,6 +2765,11 @@ fn write_char(&mut self, c: char) -> fmt::Result {
         self.push(c);
         Ok(())
     }
+
+    #[inline]
+    fn reserve_hint(&mut self, additional: usize) {
+        self.reserve(additional);
+    }
 }
 
 /// A draining iterator for `String`.
@@ -321,3 +321,13 @@ fn test_refcell() {
     drop(borrow);
     assert_eq!(format!("{:?}", refcell), "RefCell { value: 5 }");
 }
+
+#[test]
+fn test_write_bytes() {
+    use std::fmt::WriteBytes;
+
+    let mut buf = b"> ".to_vec();
+    write!(WriteBytes(&mut buf), "{:>3}|{:?}", 'é', "ü").unwrap();
+    WriteBytes(&mut buf).write_char('!').unwrap();
+    assert_eq!(buf, ">   é|\"ü\"!".as_bytes());
+}
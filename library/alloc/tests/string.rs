@@ -44,6 +44,29 @@ fn test_unsized_to_string() {
     let _: String = (*s).to_string();
 }
 
+#[test]
+fn test_primitive_to_string() {
+    assert_eq!(true.to_string(), "true");
+    assert_eq!(false.to_string(), "false");
+    assert_eq!(0u32.to_string(), "0");
+    assert_eq!(u16::MAX.to_string(), "65535");
+    assert_eq!(u128::MAX.to_string(), format!("{}", u128::MAX));
+    assert_eq!(i32::MIN.to_string(), "-2147483648");
+    assert_eq!(i128::MIN.to_string(), format!("{}", i128::MIN));
+    assert_eq!((-7isize).to_string(), "-7");
+    for n in [9, 10, 99, 100, 9_999, 10_000, 99_999_999, u64::MAX] {
+        assert_eq!(n.to_string(), format!("{}", n));
+    }
+    assert_eq!(i64::MIN.to_string(), "-9223372036854775808");
+    assert_eq!(10u128.pow(19).to_string(), format!("{}", 10u128.pow(19)));
+    assert_eq!((-10i128.pow(38)).to_string(), format!("{}", -10i128.pow(38)));
+    assert_eq!(1.0f64.to_string(), "1");
+    assert_eq!((-0.1f32).to_string(), "-0.1");
+    assert_eq!(1e21f64.to_string(), format!("{}", 1e21f64));
+    assert_eq!(f64::NAN.to_string(), "NaN");
+    assert_eq!(f32::NEG_INFINITY.to_string(), "-inf");
+}
+
 #[test]
 fn test_from_utf8() {
     let xs = b"hello".to_vec();
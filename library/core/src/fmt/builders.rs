@@ -30,24 +30,24 @@ fn wrap<'slot, 'fmt: 'buf + 'slot>(
     }
 }
 
-impl fmt::Write for PadAdapter<'_, '_> {
-    fn write_str(&mut self, mut s: &str) -> fmt::Result {
+impl PadAdapterState {
+    fn write_str(&mut self, buf: &mut dyn fmt::Write, mut s: &str) -> fmt::Result {
         while !s.is_empty() {
-            if self.state.on_newline {
-                self.buf.write_str("    ")?;
+            if self.on_newline {
+                buf.write_str("    ")?;
             }
 
             let split = match s.find('\n') {
                 Some(pos) => {
-                    self.state.on_newline = true;
+                    self.on_newline = true;
                     pos + 1
                 }
                 None => {
-                    self.state.on_newline = false;
+                    self.on_newline = false;
                     s.len()
                 }
             };
-            self.buf.write_str(&s[..split])?;
+            buf.write_str(&s[..split])?;
             s = &s[split..];
         }
 
@@ -55,6 +55,73 @@ fn write_str(&mut self, mut s: &str) -> fmt::Result {
     }
 }
 
+impl fmt::Write for PadAdapter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.state.write_str(self.buf, s)
+    }
+}
+
+/// A writer that indents every line written through it by four spaces.
+///
+/// This is the same indentation adapter used by the [`Formatter`] debug
+/// builders for pretty-printed (`{:#?}`) output, so custom [`Debug`]
+/// implementations can produce nested output that lines up with it.
+///
+/// The first line is indented as well, and a line is only indented once
+/// something is written to it.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_indented)]
+/// use std::fmt::{self, Indented, Write};
+///
+/// struct Block(Vec<&'static str>);
+///
+/// impl fmt::Debug for Block {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("block {\n")?;
+///         let mut inner = Indented::new(f);
+///         for stmt in &self.0 {
+///             writeln!(inner, "{:#?};", stmt)?;
+///         }
+///         f.write_str("}")
+///     }
+/// }
+///
+/// assert_eq!(
+///     format!("{:?}", Block(vec!["a", "b"])),
+///     "block {\n    \"a\";\n    \"b\";\n}",
+/// );
+/// ```
+#[unstable(feature = "fmt_indented", issue = "none")]
+pub struct Indented<'buf> {
+    buf: &'buf mut (dyn fmt::Write + 'buf),
+    state: PadAdapterState,
+}
+
+impl<'buf> Indented<'buf> {
+    /// Creates a new adapter writing indented output to `buf`.
+    #[unstable(feature = "fmt_indented", issue = "none")]
+    pub fn new(buf: &'buf mut (dyn fmt::Write + 'buf)) -> Self {
+        Indented { buf, state: PadAdapterState::default() }
+    }
+}
+
+#[unstable(feature = "fmt_indented", issue = "none")]
+impl fmt::Write for Indented<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.state.write_str(self.buf, s)
+    }
+}
+
+#[unstable(feature = "fmt_indented", issue = "none")]
+impl fmt::Debug for Indented<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Indented").field("on_newline", &self.state.on_newline).finish()
+    }
+}
+
 /// A struct to help with [`fmt::Debug`](Debug) implementations.
 ///
 /// This is useful when you wish to output a formatted struct as a part of your
@@ -940,3 +1007,56 @@ fn is_pretty(&self) -> bool {
         self.fmt.alternate()
     }
 }
+
+/// Creates a type whose [`fmt::Debug`] and [`fmt::Display`] impls are provided
+/// by the function `f`.
+///
+/// The returned value owns `f`, so unlike [`format_args!`] it can be returned
+/// from functions and stored; nothing is formatted until it is used. See also
+/// the [`lazy_format!`] macro, which builds one from a format string.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(lazy_format)]
+/// use std::fmt;
+///
+/// fn greeting(name: String) -> impl fmt::Display {
+///     fmt::from_fn(move |f| write!(f, "Hello, {}!", name))
+/// }
+///
+/// assert_eq!(format!("{:?}", greeting("world".to_string())), "Hello, world!");
+/// assert_eq!(greeting("you".to_string()).to_string(), "Hello, you!");
+/// ```
+#[unstable(feature = "lazy_format", issue = "none")]
+pub fn from_fn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+/// Implements [`fmt::Debug`] and [`fmt::Display`] using a function.
+///
+/// Created with [`from_fn`] or [`lazy_format!`].
+#[unstable(feature = "lazy_format", issue = "none")]
+pub struct FromFn<F>(F)
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+#[unstable(feature = "lazy_format", issue = "none")]
+impl<F> fmt::Debug for FromFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+#[unstable(feature = "lazy_format", issue = "none")]
+impl<F> fmt::Display for FromFn<F>
+where
+    F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
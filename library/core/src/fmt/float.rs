@@ -1,4 +1,6 @@
-use crate::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use crate::fmt::{
+    Debug, Display, Formatter, LowerExp, LowerHex, Result, UpperExp, UpperHex, Write,
+};
 use crate::mem::MaybeUninit;
 use crate::num::flt2dec;
 
@@ -36,6 +38,20 @@ fn float_to_decimal_common_shortest<T>(
     sign: flt2dec::Sign,
     precision: usize,
 ) -> Result
+where
+    T: flt2dec::DecodableFloat,
+{
+    float_to_decimal_shortest(num, sign, precision, |formatted| fmt.pad_formatted_parts(formatted))
+}
+
+// Runs `f` on the shortest decimal representation of `num` that round-trips,
+// with at least `precision` fractional digits.
+fn float_to_decimal_shortest<T, R>(
+    num: &T,
+    sign: flt2dec::Sign,
+    precision: usize,
+    f: impl FnOnce(&flt2dec::Formatted<'_>) -> R,
+) -> R
 where
     T: flt2dec::DecodableFloat,
 {
@@ -50,7 +66,25 @@ fn float_to_decimal_common_shortest<T>(
         &mut buf,
         &mut parts,
     );
-    fmt.pad_formatted_parts(&formatted)
+    f(&formatted)
+}
+
+/// Writes `num` the way its `Display` impl does when no formatting options
+/// are set, hinting the exact length to `buf` first.
+///
+/// `ToString` for `f32` and `f64` in `alloc` calls this. It shares
+/// `float_to_decimal_shortest` with `Display`, so the two cannot diverge.
+#[doc(hidden)]
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+#[inline(never)]
+pub fn float_to_decimal_display<T>(buf: &mut dyn Write, num: &T) -> Result
+where
+    T: flt2dec::DecodableFloat,
+{
+    float_to_decimal_shortest(num, flt2dec::Sign::Minus, 0, |formatted| {
+        buf.reserve_hint(formatted.len());
+        super::write_formatted_parts(buf, formatted)
+    })
 }
 
 // Common code of floating point Debug and Display.
@@ -178,3 +212,144 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
 
 floating! { f32 }
 floating! { f64 }
+
+/// A wrapper that formats a float in hexadecimal scientific notation.
+///
+/// With [`LowerHex`] (`{:x}`) or [`UpperHex`] (`{:X}`), the value is written
+/// like C's `%a`/`%A` conversions: an optional sign, `0x`, a leading `1`
+/// (or `0` for zero and subnormal numbers), the remaining significand bits as
+/// hexadecimal digits without trailing zeros, and a binary exponent written
+/// in decimal after `p`. The output is exact, so it round-trips losslessly.
+///
+/// Infinities and NaN are written as `inf` and `NaN`, as for [`Display`].
+/// Width, fill, alignment and the `+` and `0` flags are honored; zero
+/// padding goes between `0x` and the digits. Precision is ignored.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_hex_float)]
+/// use std::fmt::HexFloat;
+///
+/// assert_eq!(format!("{:x}", HexFloat(1.0f64)), "0x1p+0");
+/// assert_eq!(format!("{:x}", HexFloat(-0.1f64)), "-0x1.999999999999ap-4");
+/// assert_eq!(format!("{:X}", HexFloat(255.5f32)), "0X1.FFP+7");
+/// assert_eq!(format!("{:x}", HexFloat(f64::MIN_POSITIVE / 2.0)), "0x0.8p-1022");
+/// assert_eq!(format!("{:+012x}", HexFloat(3.0f32)), "+0x0001.8p+1");
+/// ```
+#[unstable(feature = "fmt_hex_float", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct HexFloat<T>(pub T);
+
+// Common code of hexadecimal float formatting. `bits` holds the raw IEEE 754
+// representation, with `mantissa_bits` significand bits below `exp_bits`
+// exponent bits and the sign bit.
+fn float_to_hex_common(
+    fmt: &mut Formatter<'_>,
+    bits: u64,
+    exp_bits: u32,
+    mantissa_bits: u32,
+    upper: bool,
+) -> Result {
+    let negative = bits >> (exp_bits + mantissa_bits) & 1 != 0;
+    let exp_max = (1 << exp_bits) - 1;
+    let biased_exp = (bits >> mantissa_bits & exp_max) as i32;
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+    let bias = (exp_max >> 1) as i32;
+
+    let sign_plus = fmt.sign_plus();
+    // The sign also carries the `0x` prefix, so that sign-aware zero padding
+    // ends up between the prefix and the digits.
+    let sign = match (negative, sign_plus, upper) {
+        (true, _, false) => "-0x",
+        (true, _, true) => "-0X",
+        (false, true, false) => "+0x",
+        (false, true, true) => "+0X",
+        (false, false, false) => "0x",
+        (false, false, true) => "0X",
+    };
+
+    let mut buf = [0; 32];
+    let (sign, len) = if biased_exp as u64 == exp_max {
+        let (sign, special): (_, &[u8]) = if mantissa != 0 {
+            ("", b"NaN")
+        } else if negative {
+            ("-", b"inf")
+        } else if sign_plus {
+            ("+", b"inf")
+        } else {
+            ("", b"inf")
+        };
+        buf[..special.len()].copy_from_slice(special);
+        (sign, special.len())
+    } else {
+        let (leading, exp) = match (biased_exp, mantissa) {
+            (0, 0) => (b'0', 0),
+            (0, _) => (b'0', 1 - bias),
+            _ => (b'1', biased_exp - bias),
+        };
+        let digits: &[u8; 16] = if upper { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+
+        let mut len = 0;
+        buf[len] = leading;
+        len += 1;
+
+        // Align the significand to whole hex digits, then drop trailing zeros.
+        let num_digits = (mantissa_bits + 3) / 4;
+        let mut frac = mantissa << (num_digits * 4 - mantissa_bits);
+        let mut num_digits = num_digits;
+        while num_digits > 0 && frac & 0xf == 0 {
+            frac >>= 4;
+            num_digits -= 1;
+        }
+        if num_digits > 0 {
+            buf[len] = b'.';
+            len += 1;
+            for i in (0..num_digits).rev() {
+                buf[len] = digits[(frac >> (i * 4) & 0xf) as usize];
+                len += 1;
+            }
+        }
+
+        buf[len] = if upper { b'P' } else { b'p' };
+        len += 1;
+        buf[len] = if exp < 0 { b'-' } else { b'+' };
+        len += 1;
+        let mut exp = exp.unsigned_abs();
+        let exp_start = len;
+        loop {
+            buf[len] = b'0' + (exp % 10) as u8;
+            len += 1;
+            exp /= 10;
+            if exp == 0 {
+                break;
+            }
+        }
+        buf[exp_start..len].reverse();
+        (sign, len)
+    };
+
+    let parts = [flt2dec::Part::Copy(&buf[..len])];
+    fmt.pad_formatted_parts(&flt2dec::Formatted { sign, parts: &parts })
+}
+
+macro_rules! hex_floating {
+    ($ty:ident, $exp_bits:expr, $mantissa_bits:expr) => {
+        #[unstable(feature = "fmt_hex_float", issue = "none")]
+        impl LowerHex for HexFloat<$ty> {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                float_to_hex_common(fmt, self.0.to_bits() as u64, $exp_bits, $mantissa_bits, false)
+            }
+        }
+
+        #[unstable(feature = "fmt_hex_float", issue = "none")]
+        impl UpperHex for HexFloat<$ty> {
+            fn fmt(&self, fmt: &mut Formatter<'_>) -> Result {
+                float_to_hex_common(fmt, self.0.to_bits() as u64, $exp_bits, $mantissa_bits, true)
+            }
+        }
+    };
+}
+
+hex_floating! { f32, 8, 23 }
+hex_floating! { f64, 11, 52 }
@@ -31,8 +31,43 @@ pub enum Alignment {
     Center,
 }
 
+/// The sign requested by a [`Formatter`], as returned by [`Formatter::sign`].
+#[unstable(feature = "formatting_options", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Sign {
+    /// Represents the `+` flag.
+    Plus,
+    /// Represents the `-` flag.
+    Minus,
+}
+
+/// Whether `Debug` output of integers was requested in hexadecimal, as
+/// returned by [`Formatter::debug_hex`].
+#[unstable(feature = "formatting_options", issue = "none")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DebugAsHex {
+    /// Represents the `x?` type, using lower-case hexadecimal digits.
+    Lower,
+    /// Represents the `X?` type, using upper-case hexadecimal digits.
+    Upper,
+}
+
+#[unstable(feature = "fmt_indented", issue = "none")]
+pub use self::builders::Indented;
+#[unstable(feature = "lazy_format", issue = "none")]
+pub use self::builders::{from_fn, FromFn};
 #[stable(feature = "debug_builders", since = "1.2.0")]
 pub use self::builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
+#[doc(hidden)]
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+pub use self::float::float_to_decimal_display;
+#[unstable(feature = "fmt_hex_float", issue = "none")]
+pub use self::float::HexFloat;
+#[unstable(feature = "fmt_radix", issue = "none")]
+pub use self::num::{radix, RadixFmt};
+#[doc(hidden)]
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+pub use self::num::{DecimalInt, DECIMAL_INT_BUF_LEN};
 
 #[unstable(feature = "fmt_internals", reason = "internal to format_args!", issue = "none")]
 #[doc(hidden)]
@@ -183,6 +218,84 @@ fn write_char(&mut self, c: char) -> Result {
     fn write_fmt(mut self: &mut Self, args: Arguments<'_>) -> Result {
         write(&mut self, args)
     }
+
+    /// Hints that about `additional` more bytes are about to be written.
+    ///
+    /// Writers that buffer their output in memory, such as `String`, can use
+    /// this to grow their buffer once up front instead of repeatedly while
+    /// formatting. The hint is only an estimate: more or fewer bytes may be
+    /// written afterwards. [`write`] calls this with the estimated length of
+    /// the formatted [`Arguments`] before writing anything, unless they
+    /// contain no placeholders and are written with a single `write_str`.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_write_reserve_hint)]
+    /// use std::fmt::Write;
+    ///
+    /// let mut buf = String::new();
+    /// buf.reserve_hint(64);
+    /// assert!(buf.capacity() >= 64);
+    /// ```
+    #[unstable(feature = "fmt_write_reserve_hint", issue = "none")]
+    #[inline]
+    fn reserve_hint(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
+    /// Like [`write_fmt`], but returns the number of bytes that were written.
+    ///
+    /// This is useful for sinks with a length limit, such as fixed-size
+    /// buffers or network frames, which need to know how much output was
+    /// produced without wrapping the writer in a counting adapter.
+    ///
+    /// On error, the bytes written so far are not reported.
+    ///
+    /// [`write_fmt`]: Write::write_fmt
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_write_counted)]
+    /// use std::fmt::Write;
+    ///
+    /// let mut buf = String::from("> ");
+    /// let written = buf.write_fmt_counted(format_args!("{}, {}!", "Hello", "мир")).unwrap();
+    /// assert_eq!(written, 14);
+    /// assert_eq!(buf, "> Hello, мир!");
+    /// ```
+    #[unstable(feature = "fmt_write_counted", issue = "none")]
+    fn write_fmt_counted(&mut self, args: Arguments<'_>) -> result::Result<usize, Error> {
+        struct Counter<'a, W: ?Sized> {
+            inner: &'a mut W,
+            written: usize,
+        }
+
+        impl<W: Write + ?Sized> Write for Counter<'_, W> {
+            fn write_str(&mut self, s: &str) -> Result {
+                self.inner.write_str(s)?;
+                self.written += s.len();
+                Ok(())
+            }
+
+            fn write_char(&mut self, c: char) -> Result {
+                self.inner.write_char(c)?;
+                self.written += c.len_utf8();
+                Ok(())
+            }
+
+            fn reserve_hint(&mut self, additional: usize) {
+                self.inner.reserve_hint(additional)
+            }
+        }
+
+        let mut counter = Counter { inner: self, written: 0 };
+        write(&mut counter, args)?;
+        Ok(counter.written)
+    }
 }
 
 #[stable(feature = "fmt_write_blanket_impl", since = "1.4.0")]
@@ -198,6 +311,14 @@ fn write_char(&mut self, c: char) -> Result {
     fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
         (**self).write_fmt(args)
     }
+
+    fn reserve_hint(&mut self, additional: usize) {
+        (**self).reserve_hint(additional)
+    }
+
+    fn write_fmt_counted(&mut self, args: Arguments<'_>) -> result::Result<usize, Error> {
+        (**self).write_fmt_counted(args)
+    }
 }
 
 /// Configuration for formatting.
@@ -230,8 +351,9 @@ impl<'a> Formatter<'a> {
     ///
     /// Currently not intended for use outside of the standard library.
     #[unstable(feature = "fmt_internals", reason = "internal to standard library", issue = "none")]
+    #[rustc_const_unstable(feature = "const_fmt_formatter_new", issue = "none")]
     #[doc(hidden)]
-    pub fn new(buf: &'a mut (dyn Write + 'a)) -> Formatter<'a> {
+    pub const fn new(buf: &'a mut (dyn Write + 'a)) -> Formatter<'a> {
         Formatter {
             flags: 0,
             fill: ' ',
@@ -334,7 +456,8 @@ impl<'a> Arguments<'a> {
     #[doc(hidden)]
     #[inline]
     #[unstable(feature = "fmt_internals", reason = "internal to format_args!", issue = "none")]
-    pub fn new_v1(pieces: &'a [&'static str], args: &'a [ArgumentV1<'a>]) -> Arguments<'a> {
+    #[rustc_const_unstable(feature = "const_fmt_arguments_new", issue = "none")]
+    pub const fn new_v1(pieces: &'a [&'static str], args: &'a [ArgumentV1<'a>]) -> Arguments<'a> {
         Arguments { pieces, fmt: None, args }
     }
 
@@ -443,6 +566,15 @@ impl<'a> Arguments<'a> {
     /// assert_eq!(format_args!("").as_str(), Some(""));
     /// assert_eq!(format_args!("{}", 1).as_str(), None);
     /// ```
+    ///
+    /// Literal-only templates can also be inspected in const contexts:
+    ///
+    /// ```rust
+    /// #![feature(const_arguments_as_str, const_fmt_arguments_new)]
+    ///
+    /// const GREETING: Option<&str> = format_args!("hello").as_str();
+    /// assert_eq!(GREETING, Some("hello"));
+    /// ```
     #[stable(feature = "fmt_as_str", since = "1.52.0")]
     #[rustc_const_unstable(feature = "const_arguments_as_str", issue = "none")]
     #[inline]
@@ -453,6 +585,46 @@ pub const fn as_str(&self) -> Option<&'static str> {
             _ => None,
         }
     }
+
+    /// Returns `true` if the template has no placeholders, so that formatting
+    /// it only writes literal text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(fmt_arguments_queries)]
+    ///
+    /// assert!(format_args!("hello {{}}").is_literal_only());
+    /// assert!(!format_args!("hello {}", 1).is_literal_only());
+    /// ```
+    #[unstable(feature = "fmt_arguments_queries", issue = "none")]
+    #[inline]
+    pub fn is_literal_only(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns `true` if the template consists of exactly one placeholder with
+    /// no formatting options and no literal text around it, as in
+    /// `format_args!("{}", x)` or `format_args!("{:?}", x)`.
+    ///
+    /// Formatting such `Arguments` is equivalent to formatting the argument
+    /// itself with default options.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(fmt_arguments_queries)]
+    ///
+    /// assert!(format_args!("{}", 1).is_single_value());
+    /// assert!(!format_args!("{:5}", 1).is_single_value());
+    /// assert!(!format_args!("x={}", 1).is_single_value());
+    /// assert!(!format_args!("{}{}", 1, 2).is_single_value());
+    /// ```
+    #[unstable(feature = "fmt_arguments_queries", issue = "none")]
+    #[inline]
+    pub fn is_single_value(&self) -> bool {
+        self.fmt.is_none() && self.args.len() == 1 && matches!(self.pieces, [""])
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -1099,6 +1271,13 @@ pub trait UpperExp {
 /// [`write!`]: crate::write!
 #[stable(feature = "rust1", since = "1.0.0")]
 pub fn write(output: &mut dyn Write, args: Arguments<'_>) -> Result {
+    // Literal-only templates, like `write!(f, "literal")`, need neither a
+    // `Formatter` nor a capacity hint. An empty template writes nothing.
+    if let Some(s) = args.as_str() {
+        return if s.is_empty() { Ok(()) } else { output.write_str(s) };
+    }
+
+    output.reserve_hint(args.estimated_capacity());
     let mut formatter = Formatter::new(output);
     let mut idx = 0;
 
@@ -1136,6 +1315,60 @@ pub fn write(output: &mut dyn Write, args: Arguments<'_>) -> Result {
     Ok(())
 }
 
+/// Formats `args` into a possibly uninitialized byte buffer.
+///
+/// This avoids having to zero-initialize large stack buffers just to
+/// format into them. On success, returns the formatted output, which
+/// occupies the start of `buf`; the rest of `buf` is left untouched.
+///
+/// # Errors
+///
+/// Returns an error if the output doesn't fit in `buf`, or if a formatting
+/// trait implementation returns an error. In that case `buf` may hold a
+/// partially written prefix of the output.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_write_uninit)]
+/// use std::fmt;
+/// use std::mem::MaybeUninit;
+///
+/// let mut buf = [MaybeUninit::uninit(); 16];
+/// let s = fmt::write_to_uninit(&mut buf, format_args!("{}-{}", 12, 34)).unwrap();
+/// assert_eq!(s, "12-34");
+///
+/// let mut small = [MaybeUninit::uninit(); 4];
+/// assert!(fmt::write_to_uninit(&mut small, format_args!("{}", 12345)).is_err());
+/// ```
+#[unstable(feature = "fmt_write_uninit", issue = "none")]
+pub fn write_to_uninit<'b>(
+    buf: &'b mut [mem::MaybeUninit<u8>],
+    args: Arguments<'_>,
+) -> result::Result<&'b mut str, Error> {
+    struct UninitWriter<'b> {
+        buf: &'b mut [mem::MaybeUninit<u8>],
+        len: usize,
+    }
+
+    impl Write for UninitWriter<'_> {
+        fn write_str(&mut self, s: &str) -> Result {
+            let dst = self.buf.get_mut(self.len..self.len + s.len()).ok_or(Error)?;
+            mem::MaybeUninit::write_slice(dst, s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    let mut writer = UninitWriter { buf, len: 0 };
+    write(&mut writer, args)?;
+    let UninitWriter { buf, len } = writer;
+    // SAFETY: `buf[..len]` was initialized by `write_str` above.
+    let init = unsafe { mem::MaybeUninit::slice_assume_init_mut(&mut buf[..len]) };
+    // SAFETY: `write_str` only ever copies in whole `str`s.
+    Ok(unsafe { str::from_utf8_unchecked_mut(init) })
+}
+
 unsafe fn run(fmt: &mut Formatter<'_>, arg: &rt::v1::Argument, args: &[ArgumentV1<'_>]) -> Result {
     fmt.fill = arg.format.fill;
     fmt.align = arg.format.align;
@@ -1184,13 +1417,40 @@ fn new(fill: char, padding: usize) -> PostPadding {
 
     /// Write this post padding.
     fn write(self, buf: &mut dyn Write) -> Result {
-        for _ in 0..self.padding {
-            buf.write_char(self.fill)?;
-        }
-        Ok(())
+        write_fill(buf, self.fill, self.padding)
     }
 }
 
+/// Writes `count` copies of `fill` to `buf`.
+///
+/// The fill character is encoded only once and then written in chunks, so
+/// wide padding costs a few `write_str` calls instead of one `write_char`
+/// call per character.
+fn write_fill(buf: &mut dyn Write, fill: char, mut count: usize) -> Result {
+    const CHUNK: usize = 16;
+
+    if count == 0 {
+        return Ok(());
+    }
+
+    let len = fill.len_utf8();
+    let mut bytes = [0; 4 * CHUNK];
+    let copies = count.min(CHUNK);
+    for i in 0..copies {
+        fill.encode_utf8(&mut bytes[i * len..]);
+    }
+    // SAFETY: `bytes[..copies * len]` holds `copies` complete UTF-8
+    // encodings of `fill`.
+    let chunk = unsafe { str::from_utf8_unchecked(&bytes[..copies * len]) };
+
+    while count > CHUNK {
+        buf.write_str(chunk)?;
+        count -= CHUNK;
+    }
+    // `count <= copies` here, so this slices on a char boundary.
+    buf.write_str(&chunk[..count * len])
+}
+
 impl<'a> Formatter<'a> {
     fn wrap_buf<'b, 'c, F>(&'b mut self, wrap: F) -> Formatter<'c>
     where
@@ -1259,7 +1519,63 @@ fn wrap_buf<'b, 'c, F>(&'b mut self, wrap: F) -> Formatter<'c>
     /// ```
     #[stable(feature = "rust1", since = "1.0.0")]
     pub fn pad_integral(&mut self, is_nonnegative: bool, prefix: &str, buf: &str) -> Result {
-        let mut width = buf.len();
+        self.pad_integral_with(is_nonnegative, prefix, buf.len(), |out| out.write_str(buf))
+    }
+
+    /// Like [`pad_integral`], but writes the digits through a callback instead
+    /// of taking them as a single string slice.
+    ///
+    /// This lets types whose digits are produced in several chunks, such as
+    /// big integers, format themselves without first collecting the digits
+    /// into a temporary buffer. The padding is computed from `digit_count`,
+    /// which must be the number of characters `write_digits` emits.
+    ///
+    /// # Arguments
+    ///
+    /// * is_nonnegative - whether the original integer was either positive or zero.
+    /// * prefix - if the '#' character (Alternate) is provided, this
+    ///   is the prefix to put in front of the number. It may be of any length.
+    /// * digit_count - the number of characters written by `write_digits`.
+    /// * write_digits - writes the digits, without sign or prefix.
+    ///
+    /// [`pad_integral`]: Formatter::pad_integral
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_pad_integral_with)]
+    /// use std::fmt;
+    ///
+    /// // A number stored as base-1000 limbs, most significant first.
+    /// struct Limbs(Vec<u16>);
+    ///
+    /// impl fmt::Display for Limbs {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         let (first, rest) = self.0.split_first().unwrap();
+    ///         let digit_count = first.to_string().len() + 3 * rest.len();
+    ///         f.pad_integral_with(true, "", digit_count, |out| {
+    ///             write!(out, "{}", first)?;
+    ///             rest.iter().try_for_each(|limb| write!(out, "{:03}", limb))
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(format!("{}", Limbs(vec![12, 345, 6])), "12345006");
+    /// assert_eq!(format!("{:>+10}", Limbs(vec![1, 0])), "     +1000");
+    /// assert_eq!(format!("{:06}", Limbs(vec![7, 0])), "007000");
+    /// ```
+    #[unstable(feature = "fmt_pad_integral_with", issue = "none")]
+    pub fn pad_integral_with<F>(
+        &mut self,
+        is_nonnegative: bool,
+        prefix: &str,
+        digit_count: usize,
+        write_digits: F,
+    ) -> Result
+    where
+        F: FnOnce(&mut dyn Write) -> Result,
+    {
+        let mut width = digit_count;
 
         let mut sign = None;
         if !is_nonnegative {
@@ -1292,13 +1608,13 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
             // write the bytes.
             None => {
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)
+                write_digits(self.buf)
             }
             // Check if we're over the minimum width, if so then we can also
             // just write the bytes.
             Some(min) if width >= min => {
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)
+                write_digits(self.buf)
             }
             // The sign and prefix goes before the padding if the fill character
             // is zero
@@ -1307,7 +1623,7 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
                 let old_align = crate::mem::replace(&mut self.align, rt::v1::Alignment::Right);
                 write_prefix(self, sign, prefix)?;
                 let post_padding = self.padding(min - width, rt::v1::Alignment::Right)?;
-                self.buf.write_str(buf)?;
+                write_digits(self.buf)?;
                 post_padding.write(self.buf)?;
                 self.fill = old_fill;
                 self.align = old_align;
@@ -1317,7 +1633,7 @@ fn write_prefix(f: &mut Formatter<'_>, sign: Option<char>, prefix: Option<&str>)
             Some(min) => {
                 let post_padding = self.padding(min - width, rt::v1::Alignment::Right)?;
                 write_prefix(self, sign, prefix)?;
-                self.buf.write_str(buf)?;
+                write_digits(self.buf)?;
                 post_padding.write(self.buf)
             }
         }
@@ -1413,9 +1729,7 @@ fn padding(
             rt::v1::Alignment::Center => (padding / 2, (padding + 1) / 2),
         };
 
-        for _ in 0..pre_pad {
-            self.buf.write_char(self.fill)?;
-        }
+        write_fill(self.buf, self.fill, pre_pad)?;
 
         Ok(PostPadding::new(self.fill, post_pad))
     }
@@ -1464,49 +1778,7 @@ fn pad_formatted_parts(&mut self, formatted: &flt2dec::Formatted<'_>) -> Result
     }
 
     fn write_formatted_parts(&mut self, formatted: &flt2dec::Formatted<'_>) -> Result {
-        fn write_bytes(buf: &mut dyn Write, s: &[u8]) -> Result {
-            // SAFETY: This is used for `flt2dec::Part::Num` and `flt2dec::Part::Copy`.
-            // It's safe to use for `flt2dec::Part::Num` since every char `c` is between
-            // `b'0'` and `b'9'`, which means `s` is valid UTF-8.
-            // It's also probably safe in practice to use for `flt2dec::Part::Copy(buf)`
-            // since `buf` should be plain ASCII, but it's possible for someone to pass
-            // in a bad value for `buf` into `flt2dec::to_shortest_str` since it is a
-            // public function.
-            // FIXME: Determine whether this could result in UB.
-            buf.write_str(unsafe { str::from_utf8_unchecked(s) })
-        }
-
-        if !formatted.sign.is_empty() {
-            self.buf.write_str(formatted.sign)?;
-        }
-        for part in formatted.parts {
-            match *part {
-                flt2dec::Part::Zero(mut nzeroes) => {
-                    const ZEROES: &str = // 64 zeroes
-                        "0000000000000000000000000000000000000000000000000000000000000000";
-                    while nzeroes > ZEROES.len() {
-                        self.buf.write_str(ZEROES)?;
-                        nzeroes -= ZEROES.len();
-                    }
-                    if nzeroes > 0 {
-                        self.buf.write_str(&ZEROES[..nzeroes])?;
-                    }
-                }
-                flt2dec::Part::Num(mut v) => {
-                    let mut s = [0; 5];
-                    let len = part.len();
-                    for c in s[..len].iter_mut().rev() {
-                        *c = b'0' + (v % 10) as u8;
-                        v /= 10;
-                    }
-                    write_bytes(self.buf, &s[..len])?;
-                }
-                flt2dec::Part::Copy(buf) => {
-                    write_bytes(self.buf, buf)?;
-                }
-            }
-        }
-        Ok(())
+        write_formatted_parts(self.buf, formatted)
     }
 
     /// Writes some data to the underlying buffer contained within this
@@ -1811,6 +2083,79 @@ pub fn sign_aware_zero_pad(&self) -> bool {
         self.flags & (1 << FlagV1::SignAwareZeroPad as u32) != 0
     }
 
+    /// Returns the sign flag that was specified, if any.
+    ///
+    /// The `+` flag takes precedence if both flags were somehow set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(formatting_options)]
+    /// use std::fmt::{self, Sign};
+    ///
+    /// struct Foo;
+    ///
+    /// impl fmt::Display for Foo {
+    ///     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    ///         match formatter.sign() {
+    ///             Some(Sign::Plus) => formatter.write_str("plus"),
+    ///             Some(Sign::Minus) => formatter.write_str("minus"),
+    ///             None => formatter.write_str("none"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(&format!("{:+}", Foo), "plus");
+    /// assert_eq!(&format!("{:-}", Foo), "minus");
+    /// assert_eq!(&format!("{}", Foo), "none");
+    /// ```
+    #[unstable(feature = "formatting_options", issue = "none")]
+    pub fn sign(&self) -> Option<Sign> {
+        if self.sign_plus() {
+            Some(Sign::Plus)
+        } else if self.sign_minus() {
+            Some(Sign::Minus)
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether `Debug` output was requested in hexadecimal with the
+    /// `x?` or `X?` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(formatting_options)]
+    /// use std::fmt::{self, DebugAsHex};
+    ///
+    /// struct Foo;
+    ///
+    /// impl fmt::Debug for Foo {
+    ///     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+    ///         match formatter.debug_hex() {
+    ///             Some(DebugAsHex::Lower) => formatter.write_str("ff"),
+    ///             Some(DebugAsHex::Upper) => formatter.write_str("FF"),
+    ///             None => formatter.write_str("255"),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(&format!("{:x?}", Foo), "ff");
+    /// assert_eq!(&format!("{:X?}", Foo), "FF");
+    /// assert_eq!(&format!("{:?}", Foo), "255");
+    /// ```
+    #[unstable(feature = "formatting_options", issue = "none")]
+    pub fn debug_hex(&self) -> Option<DebugAsHex> {
+        if self.debug_lower_hex() {
+            Some(DebugAsHex::Lower)
+        } else if self.debug_upper_hex() {
+            Some(DebugAsHex::Upper)
+        } else {
+            None
+        }
+    }
+
     // FIXME: Decide what public API we want for these two flags.
     // https://github.com/rust-lang/rust/issues/48584
     fn debug_lower_hex(&self) -> bool {
@@ -2001,6 +2346,56 @@ pub fn debug_map<'b>(&'b mut self) -> DebugMap<'b, 'a> {
     }
 }
 
+/// Writes the parts of a formatted float without any padding.
+fn write_formatted_parts(
+    buf: &mut dyn Write,
+    formatted: &flt2dec::Formatted<'_>,
+) -> Result {
+    fn write_bytes(buf: &mut dyn Write, s: &[u8]) -> Result {
+        // SAFETY: This is used for `flt2dec::Part::Num` and `flt2dec::Part::Copy`.
+        // It's safe to use for `flt2dec::Part::Num` since every char `c` is between
+        // `b'0'` and `b'9'`, which means `s` is valid UTF-8.
+        // It's also probably safe in practice to use for `flt2dec::Part::Copy(buf)`
+        // since `buf` should be plain ASCII, but it's possible for someone to pass
+        // in a bad value for `buf` into `flt2dec::to_shortest_str` since it is a
+        // public function.
+        // FIXME: Determine whether this could result in UB.
+        buf.write_str(unsafe { str::from_utf8_unchecked(s) })
+    }
+
+    if !formatted.sign.is_empty() {
+        buf.write_str(formatted.sign)?;
+    }
+    for part in formatted.parts {
+        match *part {
+            flt2dec::Part::Zero(mut nzeroes) => {
+                const ZEROES: &str = // 64 zeroes
+                    "0000000000000000000000000000000000000000000000000000000000000000";
+                while nzeroes > ZEROES.len() {
+                    buf.write_str(ZEROES)?;
+                    nzeroes -= ZEROES.len();
+                }
+                if nzeroes > 0 {
+                    buf.write_str(&ZEROES[..nzeroes])?;
+                }
+            }
+            flt2dec::Part::Num(mut v) => {
+                let mut s = [0; 5];
+                let len = part.len();
+                for c in s[..len].iter_mut().rev() {
+                    *c = b'0' + (v % 10) as u8;
+                    v /= 10;
+                }
+                write_bytes(buf, &s[..len])?;
+            }
+            flt2dec::Part::Copy(bytes) => {
+                write_bytes(buf, bytes)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 #[stable(since = "1.2.0", feature = "formatter_write")]
 impl Write for Formatter<'_> {
     fn write_str(&mut self, s: &str) -> Result {
@@ -2014,6 +2409,10 @@ fn write_char(&mut self, c: char) -> Result {
     fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
         write(self.buf, args)
     }
+
+    fn reserve_hint(&mut self, additional: usize) {
+        self.buf.reserve_hint(additional)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2071,12 +2470,29 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result {
     }
 }
 
+/// Whether each ASCII byte is written unchanged by `Debug for str`. `Debug for
+/// char` uses it too, with `'` escaped instead of `"`.
+static ASCII_DEBUG_UNESCAPED: [bool; 128] = {
+    let mut table = [false; 128];
+    let mut b = b' ';
+    while b <= b'~' {
+        table[b as usize] = b != b'"' && b != b'\\';
+        b += 1;
+    }
+    table
+};
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Debug for str {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         f.write_char('"')?;
         let mut from = 0;
         for (i, c) in self.char_indices() {
+            // Printable ASCII other than `"` and `\` never needs escaping, so
+            // skip the general escaping logic for it entirely.
+            if (c as u32) < 0x80 && ASCII_DEBUG_UNESCAPED[c as usize] {
+                continue;
+            }
             let esc = c.escape_debug_ext(EscapeDebugExtArgs {
                 escape_grapheme_extended: true,
                 escape_single_quote: false,
@@ -2106,6 +2522,12 @@ fn fmt(&self, f: &mut Formatter<'_>) -> Result {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Debug for char {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let c = *self;
+        if (c as u32) < 0x80 && ASCII_DEBUG_UNESCAPED[c as usize] && c != '\'' {
+            let quoted = [b'\'', c as u8, b'\''];
+            // SAFETY: `c` is ASCII, so all three bytes are ASCII.
+            return f.write_str(unsafe { str::from_utf8_unchecked(&quoted) });
+        }
         f.write_char('\'')?;
         for c in self.escape_debug_ext(EscapeDebugExtArgs {
             escape_grapheme_extended: true,
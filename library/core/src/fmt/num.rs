@@ -55,13 +55,15 @@ fn to_u128(&self) -> u128 { *self as u128 }
 #[doc(hidden)]
 trait GenericRadix: Sized {
     /// The number of digits.
-    const BASE: u8;
+    fn base(&self) -> u8;
 
     /// A radix-specific prefix string.
-    const PREFIX: &'static str;
+    fn prefix(&self) -> &'static str {
+        ""
+    }
 
     /// Converts an integer to corresponding radix digit.
-    fn digit(x: u8) -> u8;
+    fn digit(&self, x: u8) -> u8;
 
     /// Format an integer using the radix using a formatter.
     fn fmt_int<T: DisplayInt>(&self, mut x: T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -71,14 +73,14 @@ fn fmt_int<T: DisplayInt>(&self, mut x: T, f: &mut fmt::Formatter<'_>) -> fmt::R
         let is_nonnegative = x >= zero;
         let mut buf = [MaybeUninit::<u8>::uninit(); 128];
         let mut curr = buf.len();
-        let base = T::from_u8(Self::BASE);
+        let base = T::from_u8(self.base());
         if is_nonnegative {
             // Accumulate each digit of the number from the least significant
             // to the most significant figure.
             for byte in buf.iter_mut().rev() {
                 let n = x % base; // Get the current place value.
                 x = x / base; // Deaccumulate the number.
-                byte.write(Self::digit(n.to_u8())); // Store the digit in the buffer.
+                byte.write(self.digit(n.to_u8())); // Store the digit in the buffer.
                 curr -= 1;
                 if x == zero {
                     // No more digits left to accumulate.
@@ -90,7 +92,7 @@ fn fmt_int<T: DisplayInt>(&self, mut x: T, f: &mut fmt::Formatter<'_>) -> fmt::R
             for byte in buf.iter_mut().rev() {
                 let n = zero - (x % base); // Get the current place value.
                 x = x / base; // Deaccumulate the number.
-                byte.write(Self::digit(n.to_u8())); // Store the digit in the buffer.
+                byte.write(self.digit(n.to_u8())); // Store the digit in the buffer.
                 curr -= 1;
                 if x == zero {
                     // No more digits left to accumulate.
@@ -107,7 +109,7 @@ fn fmt_int<T: DisplayInt>(&self, mut x: T, f: &mut fmt::Formatter<'_>) -> fmt::R
                 buf.len(),
             ))
         };
-        f.pad_integral(is_nonnegative, Self::PREFIX, buf)
+        f.pad_integral(is_nonnegative, self.prefix(), buf)
     }
 }
 
@@ -130,12 +132,12 @@ fn fmt_int<T: DisplayInt>(&self, mut x: T, f: &mut fmt::Formatter<'_>) -> fmt::R
 macro_rules! radix {
     ($T:ident, $base:expr, $prefix:expr, $($x:pat => $conv:expr),+) => {
         impl GenericRadix for $T {
-            const BASE: u8 = $base;
-            const PREFIX: &'static str = $prefix;
-            fn digit(x: u8) -> u8 {
+            fn base(&self) -> u8 { $base }
+            fn prefix(&self) -> &'static str { $prefix }
+            fn digit(&self, x: u8) -> u8 {
                 match x {
                     $($x => $conv,)+
-                    x => panic!("number not in the range 0..={}: {}", Self::BASE - 1, x),
+                    x => panic!("number not in the range 0..={}: {}", self.base() - 1, x),
                 }
             }
         }
@@ -147,6 +149,92 @@ fn digit(x: u8) -> u8 {
 radix! { LowerHex, 16, "0x", x @  0 ..=  9 => b'0' + x, x @ 10 ..= 15 => b'a' + (x - 10) }
 radix! { UpperHex, 16, "0x", x @  0 ..=  9 => b'0' + x, x @ 10 ..= 15 => b'A' + (x - 10) }
 
+/// A radix in the range `2..=36`, using letters for digits above 9.
+#[derive(Clone, Copy, PartialEq)]
+struct Radix {
+    base: u8,
+    upper: bool,
+}
+
+impl GenericRadix for Radix {
+    fn base(&self) -> u8 {
+        self.base
+    }
+
+    fn digit(&self, x: u8) -> u8 {
+        match x {
+            x @ 0..=9 => b'0' + x,
+            x if x < self.base => (if self.upper { b'A' } else { b'a' }) + (x - 10),
+            x => panic!("number not in the range 0..={}: {}", self.base - 1, x),
+        }
+    }
+}
+
+/// A wrapper that formats an integer in an arbitrary base, created by
+/// [`radix`].
+///
+/// [`radix`]: fmt::radix
+#[unstable(feature = "fmt_radix", issue = "none")]
+#[derive(Clone, Copy, Debug)]
+pub struct RadixFmt<T> {
+    x: T,
+    base: u8,
+    upper: bool,
+}
+
+/// Formats an integer in any base from 2 to 36.
+///
+/// The returned value implements [`Display`] for every primitive integer
+/// type. Digits above 9 are written as lower-case letters, unless
+/// [`RadixFmt::uppercase`] is used. Negative numbers are written with a
+/// leading `-`, and width, fill, alignment, `+` and `0` are honored just
+/// like for decimal integers. No prefix is added for the `#` flag.
+///
+/// [`Display`]: fmt::Display
+///
+/// # Panics
+///
+/// Panics if `base` is not in the range `2..=36`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(fmt_radix)]
+/// use std::fmt::radix;
+///
+/// assert_eq!(format!("{}", radix(35u8, 36)), "z");
+/// assert_eq!(format!("{}", radix(-255i32, 16).uppercase()), "-FF");
+/// assert_eq!(format!("{:>+6}", radix(8u64, 3)), "   +22");
+/// ```
+#[unstable(feature = "fmt_radix", issue = "none")]
+pub fn radix<T>(x: T, base: u8) -> RadixFmt<T> {
+    assert!(2 <= base && base <= 36, "the base must be in the range of 2..=36: {}", base);
+    RadixFmt { x, base, upper: false }
+}
+
+impl<T> RadixFmt<T> {
+    /// Writes digits above 9 as upper-case letters.
+    #[unstable(feature = "fmt_radix", issue = "none")]
+    pub fn uppercase(self) -> Self {
+        RadixFmt { upper: true, ..self }
+    }
+}
+
+macro_rules! radix_fmt {
+    ($($T:ident)*) => {$(
+        #[unstable(feature = "fmt_radix", issue = "none")]
+        impl fmt::Display for RadixFmt<$T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                Radix { base: self.base, upper: self.upper }.fmt_int(self.x, f)
+            }
+        }
+    )*};
+}
+radix_fmt! {
+  i8 i16 i32 i64 i128 isize
+  u8 u16 u32 u64 u128 usize
+}
+
 macro_rules! int_base {
     (fmt::$Trait:ident for $T:ident as $U:ident -> $Radix:ident) => {
         #[stable(feature = "rust1", since = "1.0.0")]
@@ -199,6 +287,39 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
   u8 u16 u32 u64 u128 usize
 }
 
+/// Length of the buffer passed to [`DecimalInt::write_decimal`]: the 39
+/// digits of `u128::MAX` plus a sign.
+#[doc(hidden)]
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+pub const DECIMAL_INT_BUF_LEN: usize = 40;
+
+/// Writes an integer the way its `Display` impl does without formatting
+/// options, using the same digit writer, into a caller-provided buffer.
+///
+/// This lets `ToString` in `alloc` skip the `Formatter` without keeping a
+/// second copy of the digit loop.
+#[doc(hidden)]
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+pub trait DecimalInt: Copy {
+    /// Writes `self` at the end of `buf` and returns the written part.
+    fn write_decimal(self, buf: &mut [MaybeUninit<u8>; DECIMAL_INT_BUF_LEN]) -> &str;
+}
+
+/// Prepends a `-` to the digits in `buf[curr..]` if needed, and returns them.
+fn write_decimal_sign(
+    mut curr: usize,
+    is_nonnegative: bool,
+    buf: &mut [MaybeUninit<u8>; DECIMAL_INT_BUF_LEN],
+) -> &str {
+    if !is_nonnegative {
+        // There are at most 39 digits, so there is room for the sign.
+        curr -= 1;
+        buf[curr] = MaybeUninit::new(b'-');
+    }
+    // SAFETY: `buf[curr..]` was initialized with ASCII digits and an optional sign.
+    unsafe { str::from_utf8_unchecked(MaybeUninit::slice_assume_init_ref(&buf[curr..])) }
+}
+
 // 2 digit decimal look up table
 static DEC_DIGITS_LUT: &[u8; 200] = b"0001020304050607080910111213141516171819\
       2021222324252627282930313233343536373839\
@@ -207,18 +328,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
       8081828384858687888990919293949596979899";
 
 macro_rules! impl_Display {
-    ($($t:ident),* as $u:ident via $conv_fn:ident named $name:ident) => {
-        fn $name(mut n: $u, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            // 2^128 is about 3*10^38, so 39 gives an extra byte of space
-            let mut buf = [MaybeUninit::<u8>::uninit(); 39];
-            let mut curr = buf.len() as isize;
-            let buf_ptr = MaybeUninit::slice_as_mut_ptr(&mut buf);
+    ($($t:ident),* as $u:ident via $conv_fn:ident named $name:ident, $digits:ident) => {
+        /// Writes the decimal digits of `n` at the end of `buf`, returning the
+        /// index of the first digit.
+        fn $digits<const N: usize>(mut n: $u, buf: &mut [MaybeUninit<u8>; N]) -> usize {
+            // 2^128 is about 3*10^38, so 39 bytes always have enough space
+            assert!(N >= 39);
+            let mut curr = N as isize;
+            let buf_ptr = MaybeUninit::slice_as_mut_ptr(buf);
             let lut_ptr = DEC_DIGITS_LUT.as_ptr();
 
             // SAFETY: Since `d1` and `d2` are always less than or equal to `198`, we
             // can copy from `lut_ptr[d1..d1 + 1]` and `lut_ptr[d2..d2 + 1]`. To show
             // that it's OK to copy into `buf_ptr`, notice that at the beginning
-            // `curr == buf.len() == 39 > log(n)` since `n < 2^128 < 10^39`, and at
+            // `curr == N >= 39 > log(n)` since `n < 2^128 < 10^39`, and at
             // each step this is kept the same as `n` is divided. Since `n` is always
             // non-negative, this means that `curr > 0` so `buf_ptr[curr..curr + 1]`
             // is safe to access.
@@ -263,12 +386,16 @@ fn $name(mut n: $u, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Re
                     ptr::copy_nonoverlapping(lut_ptr.offset(d1), buf_ptr.offset(curr), 2);
                 }
             }
+            curr as usize
+        }
 
-            // SAFETY: `curr` > 0 (since we made `buf` large enough), and all the chars are valid
+        fn $name(n: $u, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let mut buf = [MaybeUninit::<u8>::uninit(); 39];
+            let curr = $digits(n, &mut buf);
+            // SAFETY: `$digits` initialized `buf[curr..]`, and all the chars are valid
             // UTF-8 since `DEC_DIGITS_LUT` is
             let buf_slice = unsafe {
-                str::from_utf8_unchecked(
-                    slice::from_raw_parts(buf_ptr.offset(curr), buf.len() - curr as usize))
+                str::from_utf8_unchecked(MaybeUninit::slice_assume_init_ref(&buf[curr..]))
             };
             f.pad_integral(is_nonnegative, "", buf_slice)
         }
@@ -286,6 +413,20 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 };
                 $name(n, is_nonnegative, f)
             }
+        }
+
+        #[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+        impl DecimalInt for $t {
+            #[allow(unused_comparisons)]
+            fn write_decimal(self, buf: &mut [MaybeUninit<u8>; DECIMAL_INT_BUF_LEN]) -> &str {
+                let is_nonnegative = self >= 0;
+                let n = if is_nonnegative {
+                    self.$conv_fn()
+                } else {
+                    (!self.$conv_fn()).wrapping_add(1)
+                };
+                write_decimal_sign($digits(n, buf), is_nonnegative, buf)
+            }
         })*
     };
 }
@@ -461,7 +602,7 @@ mod imp {
     use super::*;
     impl_Display!(
         i8, u8, i16, u16, i32, u32, i64, u64, usize, isize
-            as u64 via to_u64 named fmt_u64
+            as u64 via to_u64 named fmt_u64, u64_digits
     );
     impl_Exp!(
         i8, u8, i16, u16, i32, u32, i64, u64, usize, isize
@@ -472,8 +613,10 @@ mod imp {
 #[cfg(not(any(target_pointer_width = "64", target_arch = "wasm32")))]
 mod imp {
     use super::*;
-    impl_Display!(i8, u8, i16, u16, i32, u32, isize, usize as u32 via to_u32 named fmt_u32);
-    impl_Display!(i64, u64 as u64 via to_u64 named fmt_u64);
+    impl_Display!(
+        i8, u8, i16, u16, i32, u32, isize, usize as u32 via to_u32 named fmt_u32, u32_digits
+    );
+    impl_Display!(i64, u64 as u64 via to_u64 named fmt_u64, u64_digits);
     impl_Exp!(i8, u8, i16, u16, i32, u32, isize, usize as u32 via to_u32 named exp_u32);
     impl_Exp!(i64, u64 as u64 via to_u64 named exp_u64);
 }
@@ -587,26 +730,54 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+impl DecimalInt for u128 {
+    fn write_decimal(self, buf: &mut [MaybeUninit<u8>; DECIMAL_INT_BUF_LEN]) -> &str {
+        write_decimal_sign(u128_digits(self, buf), true, buf)
+    }
+}
+
+#[unstable(feature = "fmt_internals", reason = "internal to ToString", issue = "none")]
+impl DecimalInt for i128 {
+    fn write_decimal(self, buf: &mut [MaybeUninit<u8>; DECIMAL_INT_BUF_LEN]) -> &str {
+        let is_nonnegative = self >= 0;
+        let n = if is_nonnegative { self.to_u128() } else { (!self.to_u128()).wrapping_add(1) };
+        write_decimal_sign(u128_digits(n, buf), is_nonnegative, buf)
+    }
+}
+
+fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut buf = [MaybeUninit::<u8>::uninit(); 39];
+    let curr = u128_digits(n, &mut buf);
+    // SAFETY: `u128_digits` initialized `buf[curr..]`, and all the chars are valid
+    // UTF-8 since `DEC_DIGITS_LUT` is
+    let buf_slice =
+        unsafe { str::from_utf8_unchecked(MaybeUninit::slice_assume_init_ref(&buf[curr..])) };
+    f.pad_integral(is_nonnegative, "", buf_slice)
+}
+
 /// Specialized optimization for u128. Instead of taking two items at a time, it splits
 /// into at most 2 u64s, and then chunks by 10e16, 10e8, 10e4, 10e2, and then 10e1.
 /// It also has to handle 1 last item, as 10^40 > 2^128 > 10^39, whereas
 /// 10^20 > 2^64 > 10^19.
-fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    // 2^128 is about 3*10^38, so 39 gives an extra byte of space
-    let mut buf = [MaybeUninit::<u8>::uninit(); 39];
+///
+/// The digits are written at the end of `buf`, and the index of the first one is returned.
+fn u128_digits<const N: usize>(n: u128, buf: &mut [MaybeUninit<u8>; N]) -> usize {
+    // 2^128 is about 3*10^38, so 39 bytes always have enough space
+    assert!(N >= 39);
     let mut curr = buf.len() as isize;
 
     let (n, rem) = udiv_1e19(n);
-    parse_u64_into(rem, &mut buf, &mut curr);
+    parse_u64_into(rem, buf, &mut curr);
 
     if n != 0 {
         // 0 pad up to point
         let target = (buf.len() - 19) as isize;
         // SAFETY: Guaranteed that we wrote at most 19 bytes, and there must be space
-        // remaining since it has length 39
+        // remaining since it has at least length 39
         unsafe {
             ptr::write_bytes(
-                MaybeUninit::slice_as_mut_ptr(&mut buf).offset(target),
+                MaybeUninit::slice_as_mut_ptr(buf).offset(target),
                 b'0',
                 (curr - target) as usize,
             );
@@ -614,13 +785,13 @@ fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::R
         curr = target;
 
         let (n, rem) = udiv_1e19(n);
-        parse_u64_into(rem, &mut buf, &mut curr);
+        parse_u64_into(rem, buf, &mut curr);
         // Should this following branch be annotated with unlikely?
         if n != 0 {
             let target = (buf.len() - 38) as isize;
             // The raw `buf_ptr` pointer is only valid until `buf` is used the next time,
             // buf `buf` is not used in this scope so we are good.
-            let buf_ptr = MaybeUninit::slice_as_mut_ptr(&mut buf);
+            let buf_ptr = MaybeUninit::slice_as_mut_ptr(buf);
             // SAFETY: At this point we wrote at most 38 bytes, pad up to that point,
             // There can only be at most 1 digit remaining.
             unsafe {
@@ -630,16 +801,7 @@ fn fmt_u128(n: u128, is_nonnegative: bool, f: &mut fmt::Formatter<'_>) -> fmt::R
             }
         }
     }
-
-    // SAFETY: `curr` > 0 (since we made `buf` large enough), and all the chars are valid
-    // UTF-8 since `DEC_DIGITS_LUT` is
-    let buf_slice = unsafe {
-        str::from_utf8_unchecked(slice::from_raw_parts(
-            MaybeUninit::slice_as_mut_ptr(&mut buf).offset(curr),
-            buf.len() - curr as usize,
-        ))
-    };
-    f.pad_integral(is_nonnegative, "", buf_slice)
+    curr as usize
 }
 
 /// Partition of `n` into n > 1e19 and rem <= 1e19
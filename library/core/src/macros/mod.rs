@@ -529,6 +529,59 @@ macro_rules! writeln {
     );
 }
 
+/// Creates a value that formats its arguments lazily.
+///
+/// This takes the same positional and named arguments as [`format_args!`], but
+/// evaluates each of them once, up front, and moves the results into the
+/// returned value. The returned value implements [`fmt::Display`] and
+/// [`fmt::Debug`] and can be returned from functions or stored, which is not
+/// possible with the borrowed [`fmt::Arguments`] produced by [`format_args!`].
+/// The formatting itself only happens when the value is written out.
+///
+/// See [`fmt::from_fn`] for details on the returned value.
+///
+/// [`fmt::Display`]: crate::fmt::Display
+/// [`fmt::Debug`]: crate::fmt::Debug
+/// [`fmt::Arguments`]: crate::fmt::Arguments
+/// [`fmt::from_fn`]: crate::fmt::from_fn
+///
+/// # Examples
+///
+/// ```
+/// #![feature(lazy_format)]
+/// use std::fmt::Display;
+///
+/// fn describe(id: u32, name: String) -> impl Display {
+///     lazy_format!("#{:04} ({})", id, name)
+/// }
+///
+/// let d = describe(7, "seven".to_string());
+/// assert_eq!(d.to_string(), "#0007 (seven)");
+/// assert_eq!(format!("[{}]", d), "[#0007 (seven)]");
+/// ```
+#[macro_export]
+#[unstable(feature = "lazy_format", issue = "none")]
+#[allow_internal_unstable(lazy_format)]
+macro_rules! lazy_format {
+    // Each argument is bound to its own `arg`; hygiene keeps the names apart.
+    (@bind [$fmt:literal] [$($bound:tt)*] $name:ident = $value:expr $(, $($rest:tt)*)?) => {
+        match $value {
+            arg => $crate::lazy_format!(@bind [$fmt] [$($bound)* $name = arg,] $($($rest)*)?),
+        }
+    };
+    (@bind [$fmt:literal] [$($bound:tt)*] $value:expr $(, $($rest:tt)*)?) => {
+        match $value {
+            arg => $crate::lazy_format!(@bind [$fmt] [$($bound)* arg,] $($($rest)*)?),
+        }
+    };
+    (@bind [$fmt:literal] [$($bound:tt)*]) => {
+        $crate::fmt::from_fn(move |f| f.write_fmt($crate::format_args!($fmt, $($bound)*)))
+    };
+    ($fmt:literal $(, $($arg:tt)*)?) => {
+        $crate::lazy_format!(@bind [$fmt] [] $($($arg)*)?)
+    };
+}
+
 /// Indicates unreachable code.
 ///
 /// This is useful any time that the compiler can't determine that some code is unreachable. For
@@ -649,6 +649,41 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+mod indented {
+    use std::fmt::{self, Indented, Write};
+
+    #[test]
+    fn test_lines() {
+        let mut buf = String::new();
+        let mut w = Indented::new(&mut buf);
+        w.write_str("a\nb").unwrap();
+        w.write_str("c\n").unwrap();
+        w.write_str("\n").unwrap();
+        w.write_char('d').unwrap();
+        assert_eq!(buf, "    a\n    bc\n    \n    d");
+    }
+
+    #[test]
+    fn test_matches_pretty_debug() {
+        struct Wrapper(Vec<u32>);
+
+        impl fmt::Debug for Wrapper {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("Wrapper(\n")?;
+                let mut inner = Indented::new(f);
+                writeln!(inner, "{:#?},", self.0)?;
+                f.write_str(")")
+            }
+        }
+
+        let v = vec![1, 2];
+        assert_eq!(
+            format!("{:?}", Wrapper(v.clone())),
+            format!("{:#?}", (v,)).replace("(", "Wrapper(")
+        );
+    }
+}
+
 #[test]
 fn test_formatting_parameters_are_forwarded() {
     use std::collections::{BTreeMap, BTreeSet};
@@ -29,3 +29,40 @@ fn test_format_f32() {
     assert_eq!("0.0", format!("{:?}", 0.0f32));
     assert_eq!("1.01", format!("{:?}", 1.01f32));
 }
+
+#[test]
+fn test_format_sign_aware_zero_pad_matches_integers() {
+    // The sign always goes before the zeros, and the `0` flag overrides
+    // the requested alignment, exactly as for integers.
+    assert_eq!(format!("{:08.2}", -1.5f64), "-0001.50");
+    assert_eq!(format!("{:08}", -15i32), "-0000015");
+    assert_eq!(format!("{:+08.2}", 1.5f64), "+0001.50");
+    assert_eq!(format!("{:+08}", 15i32), "+0000015");
+    assert_eq!(format!("{:<08.2}", -1.5f64), "-0001.50");
+    assert_eq!(format!("{:<08}", -15i32), "-0000015");
+    assert_eq!(format!("{:^+08.1}", 2.0f64), "+00002.0");
+    assert_eq!(format!("{:^+08}", 2i32), "+0000002");
+    assert_eq!(format!("{:08}", -0.5f32), "-00000.5");
+    assert_eq!(format!("{:08e}", -1500.0f64), "-001.5e3");
+    assert_eq!(format!("{:08.2}", -123456.789f64), "-123456.79");
+    assert_eq!(format!("{:08}", -1234567i32), "-1234567");
+}
+
+#[test]
+fn test_format_hex_float() {
+    use core::fmt::HexFloat;
+
+    assert_eq!(format!("{:x}", HexFloat(0.0f64)), "0x0p+0");
+    assert_eq!(format!("{:x}", HexFloat(-0.0f32)), "-0x0p+0");
+    assert_eq!(format!("{:x}", HexFloat(1.5f64)), "0x1.8p+0");
+    assert_eq!(format!("{:x}", HexFloat(0.1f32)), "0x1.99999ap-4");
+    assert_eq!(format!("{:X}", HexFloat(f64::MAX)), "0X1.FFFFFFFFFFFFFP+1023");
+    assert_eq!(format!("{:x}", HexFloat(f32::MIN_POSITIVE)), "0x1p-126");
+    assert_eq!(format!("{:x}", HexFloat(f64::from_bits(1))), "0x0.0000000000001p-1022");
+    assert_eq!(format!("{:x}", HexFloat(f64::INFINITY)), "inf");
+    assert_eq!(format!("{:+x}", HexFloat(f32::INFINITY)), "+inf");
+    assert_eq!(format!("{:x}", HexFloat(f64::NEG_INFINITY)), "-inf");
+    assert_eq!(format!("{:x}", HexFloat(f64::NAN)), "NaN");
+    assert_eq!(format!("{:>10x}", HexFloat(2.0f64)), "    0x1p+1");
+    assert_eq!(format!("{:010x}", HexFloat(-2.0f64)), "-0x0001p+1");
+}
@@ -43,3 +43,189 @@ fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 
     assert_eq!(format!("{:<03}", Bar), "1  0051  ");
 }
+
+#[test]
+fn test_wide_padding() {
+    assert_eq!(format!("{:*>20}", "a"), format!("{}a", "*".repeat(19)));
+    assert_eq!(format!("{:é<18}", 1), format!("1{}", "é".repeat(17)));
+    assert_eq!(format!("{:🦀^35}", "ab"), format!("{0}ab{1}", "🦀".repeat(16), "🦀".repeat(17)));
+    assert_eq!(format!("{:->5}", "abcde"), "abcde");
+}
+
+#[test]
+fn test_const_as_str() {
+    const EMPTY: Option<&str> = format_args!("").as_str();
+    const HELLO: Option<&str> = format_args!("hello {{}}").as_str();
+    assert_eq!(EMPTY, Some(""));
+    assert_eq!(HELLO, Some("hello {}"));
+}
+
+#[test]
+fn test_pad_integral_with() {
+    struct Chunked(&'static [&'static str]);
+
+    impl core::fmt::LowerHex for Chunked {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let digit_count = self.0.iter().map(|s| s.len()).sum();
+            f.pad_integral_with(false, "0x_", digit_count, |out| {
+                self.0.iter().try_for_each(|s| out.write_str(s))
+            })
+        }
+    }
+
+    let n = Chunked(&["ab", "cd", "ef"]);
+    assert_eq!(format!("{:x}", n), "-abcdef");
+    assert_eq!(format!("{:#x}", n), "-0x_abcdef");
+    assert_eq!(format!("{:#012x}", n), "-0x_00abcdef");
+    assert_eq!(format!("{:^#12x}", n), " -0x_abcdef ");
+}
+
+#[test]
+fn test_write_fmt_counted() {
+    use core::fmt::Write;
+
+    let mut buf = String::new();
+    assert_eq!(buf.write_fmt_counted(format_args!("")), Ok(0));
+    assert_eq!(buf.write_fmt_counted(format_args!("{:>4}|{}", 'é', 1.5)), Ok(9));
+    assert_eq!((&mut buf).write_fmt_counted(format_args!("{:?}", "a")), Ok(3));
+    assert_eq!(buf, "   é|1.5\"a\"");
+}
+
+#[test]
+fn test_write_reserves_estimated_capacity() {
+    use core::fmt::Write;
+
+    struct Hinted(Vec<usize>);
+
+    impl Write for Hinted {
+        fn write_str(&mut self, _: &str) -> core::fmt::Result {
+            Ok(())
+        }
+
+        fn reserve_hint(&mut self, additional: usize) {
+            self.0.push(additional);
+        }
+    }
+
+    let mut w = Hinted(Vec::new());
+    write!(w, "Hello, {}!", "world").unwrap();
+    write!(&mut w, "Hi {}", 1).unwrap();
+    assert_eq!(w.0, [16, 6]);
+
+    let mut s = String::new();
+    write!(s, "{}. 16-bytes piece", 1).unwrap();
+    assert!(s.capacity() >= 32);
+}
+
+#[test]
+fn test_write_to_uninit() {
+    use core::fmt::write_to_uninit;
+    use core::mem::MaybeUninit;
+
+    let mut buf = [MaybeUninit::uninit(); 8];
+    assert_eq!(write_to_uninit(&mut buf, format_args!("")).unwrap(), "");
+    assert_eq!(write_to_uninit(&mut buf, format_args!("{:>4}é", 'a')).unwrap(), "   aé");
+    assert_eq!(write_to_uninit(&mut buf, format_args!("{}", 12345678)).unwrap(), "12345678");
+    assert!(write_to_uninit(&mut buf, format_args!("{}", 123456789)).is_err());
+    assert!(write_to_uninit(&mut [], format_args!("x")).is_err());
+}
+
+#[test]
+fn test_str_debug_escapes() {
+    assert_eq!(format!("{:?}", "plain ASCII ~!"), "\"plain ASCII ~!\"");
+    assert_eq!(format!("{:?}", "q\"b\\'"), "\"q\\\"b\\\\'\"");
+    assert_eq!(format!("{:?}", "\t\r\n\0\x7f"), "\"\\t\\r\\n\\u{0}\\u{7f}\"");
+    assert_eq!(format!("{:?}", "é\u{301}x"), "\"é\\u{301}x\"");
+}
+
+#[test]
+fn test_char_debug_escapes() {
+    assert_eq!(format!("{:?}", 'a'), "'a'");
+    assert_eq!(format!("{:?}", '"'), "'\"'");
+    assert_eq!(format!("{:?}", '\''), "'\\''");
+    assert_eq!(format!("{:?}", '\\'), "'\\\\'");
+    assert_eq!(format!("{:?}", '\n'), "'\\n'");
+    assert_eq!(format!("{:?}", '\x7f'), "'\\u{7f}'");
+    assert_eq!(format!("{:?}", 'é'), "'é'");
+}
+
+#[test]
+fn test_arguments_queries() {
+    assert!(format_args!("").is_literal_only());
+    assert!(format_args!("a{{b}}c").is_literal_only());
+    assert!(!format_args!("{}", 1).is_literal_only());
+    assert!(!format_args!("{:1$}", 1, 2).is_literal_only());
+
+    assert!(format_args!("{}", 1).is_single_value());
+    assert!(format_args!("{:?}", "a").is_single_value());
+    assert!(!format_args!("").is_single_value());
+    assert!(!format_args!("{}!", 1).is_single_value());
+    assert!(!format_args!("{:#?}", 1).is_single_value());
+    assert!(!format_args!("{0}{0}", 1).is_single_value());
+}
+
+#[test]
+fn test_lazy_format() {
+    use core::cell::Cell;
+
+    fn owned(n: i32) -> impl core::fmt::Display {
+        let s = String::from("owned");
+        lazy_format!("{}:{:>3}", s, n)
+    }
+    assert_eq!(format!("{}", owned(7)), "owned:  7");
+
+    let evaluated = Cell::new(0);
+    let next = || {
+        evaluated.set(evaluated.get() + 1);
+        evaluated.get()
+    };
+    let v = lazy_format!("{} {} {0}", next(), next());
+    assert_eq!(evaluated.get(), 2);
+    assert_eq!(format!("{:?}", v), "1 2 1");
+    assert_eq!(format!("{}", v), "1 2 1");
+    assert_eq!(evaluated.get(), 2);
+
+    let name = String::from("named");
+    let v = lazy_format!("{}-{name}-{0}-{n:>3}", 1, name = name, n = next());
+    assert_eq!(evaluated.get(), 3);
+    assert_eq!(v.to_string(), "1-named-1-  3");
+
+    assert_eq!(format!("{}", lazy_format!("plain")), "plain");
+    assert_eq!(format!("{}", core::fmt::from_fn(|f| f.pad("x"))), "x");
+}
+
+#[test]
+fn test_write_literal_only_skips_formatting() {
+    use core::fmt::{self, Write};
+
+    #[derive(Default)]
+    struct Calls {
+        writes: Vec<String>,
+        hints: Vec<usize>,
+    }
+
+    impl Write for Calls {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.writes.push(s.to_owned());
+            Ok(())
+        }
+
+        fn reserve_hint(&mut self, additional: usize) {
+            self.hints.push(additional);
+        }
+    }
+
+    // Literal-only templates are written directly, without a capacity hint,
+    // and an empty one writes nothing at all.
+    let mut w = Calls::default();
+    write!(w, "").unwrap();
+    write!(w, "a{{b}}c").unwrap();
+    fmt::write(&mut w, format_args!("{}", format_args!("nested"))).unwrap();
+    assert_eq!(w.writes, ["a{b}c", "nested"]);
+    assert_eq!(w.hints, [0]);
+
+    let mut w = Calls::default();
+    write!(w, "{}. 16-bytes piece", 'x').unwrap();
+    assert_eq!(w.writes, ["x", ". 16-bytes piece"]);
+    assert_eq!(w.hints, [32]);
+}
@@ -222,3 +222,25 @@ fn test_format_debug_hex() {
     assert_eq!(format!("{:02x?}", b"Foo\0"), "[46, 6f, 6f, 00]");
     assert_eq!(format!("{:02X?}", b"Foo\0"), "[46, 6F, 6F, 00]");
 }
+
+#[test]
+fn test_format_radix() {
+    use core::fmt::radix;
+
+    assert_eq!(format!("{}", radix(0u8, 2)), "0");
+    assert_eq!(format!("{}", radix(255u8, 2)), "11111111");
+    assert_eq!(format!("{}", radix(1295u16, 36)), "zz");
+    assert_eq!(format!("{}", radix(1295u16, 36).uppercase()), "ZZ");
+    assert_eq!(format!("{}", radix(-10i32, 10)), "-10");
+    assert_eq!(format!("{}", radix(i8::MIN, 2)), "-10000000");
+    assert_eq!(format!("{}", radix(u128::MAX, 16)), format!("{:x}", u128::MAX));
+    assert_eq!(format!("{:#}", radix(7usize, 7)), "10");
+    assert_eq!(format!("{:05}", radix(-5isize, 3)), "-0012");
+    assert_eq!(format!("{:<4}|", radix(9u32, 9)), "10  |");
+}
+
+#[test]
+#[should_panic]
+fn test_format_radix_base_too_large() {
+    let _ = core::fmt::radix(1u8, 37);
+}
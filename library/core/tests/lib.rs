@@ -9,7 +9,9 @@
 #![feature(cfg_panic)]
 #![feature(cfg_target_has_atomic)]
 #![feature(const_assume)]
+#![feature(const_arguments_as_str)]
 #![feature(const_cell_into_inner)]
+#![feature(const_fmt_arguments_new)]
 #![feature(const_maybe_uninit_assume_init)]
 #![feature(const_ptr_read)]
 #![feature(const_ptr_write)]
@@ -24,7 +26,15 @@
 #![feature(exact_size_is_empty)]
 #![feature(extern_types)]
 #![feature(flt2dec)]
+#![feature(fmt_arguments_queries)]
+#![feature(fmt_hex_float)]
+#![feature(fmt_indented)]
 #![feature(fmt_internals)]
+#![feature(fmt_pad_integral_with)]
+#![feature(fmt_radix)]
+#![feature(fmt_write_counted)]
+#![feature(fmt_write_reserve_hint)]
+#![feature(fmt_write_uninit)]
 #![feature(hashmap_internals)]
 #![feature(try_find)]
 #![feature(is_sorted)]
@@ -50,6 +60,7 @@
 #![feature(iter_is_partitioned)]
 #![feature(iter_order_by)]
 #![feature(iter_map_while)]
+#![feature(lazy_format)]
 #![feature(const_mut_refs)]
 #![feature(const_pin)]
 #![feature(const_slice_from_raw_parts)]
@@ -282,6 +282,7 @@
 #![feature(intra_doc_pointers)]
 #![feature(iter_zip)]
 #![feature(lang_items)]
+#![feature(lazy_format)]
 #![feature(linkage)]
 #![feature(llvm_asm)]
 #![feature(log_syntax)]
@@ -552,7 +553,8 @@ pub mod task {
 #[allow(deprecated, deprecated_in_future)]
 pub use core::{
     assert_eq, assert_matches, assert_ne, debug_assert, debug_assert_eq, debug_assert_matches,
-    debug_assert_ne, matches, r#try, todo, unimplemented, unreachable, write, writeln,
+    debug_assert_ne, lazy_format, matches, r#try, todo, unimplemented, unreachable, write,
+    writeln,
 };
 
 // Re-export built-in macros defined through libcore.